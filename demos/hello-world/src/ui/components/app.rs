@@ -1,22 +1,29 @@
 use raui_core::prelude::*;
+use raui_material::component::theme::ThemeProps;
 
 widget_component! {
-    pub app(key, named_slots) {
+    pub app(key, props, named_slots) {
         unpack_named_slots!(named_slots => { title, content });
 
-        title.remap_props(|props| props.with(FlexBoxItemLayout {
-            grow: 0.0,
-            shrink: 0.0,
-            ..Default::default()
-        }));
-        let props = Props::new(VerticalBoxProps {
-            separation: 16.0,
+        let theme = props.read_cloned_or_default::<ThemeProps>();
+
+        let title = title
+            .remap_props(|props| props.with(FlexBoxItemLayout {
+                grow: 0.0,
+                shrink: 0.0,
+                ..Default::default()
+            }))
+            .remap_shared_props(|props| props.with(theme.clone()));
+        let content = content.remap_shared_props(|props| props.with(theme.clone()));
+
+        let nav_props = Props::new(VerticalBoxProps {
+            separation: theme.content_separation,
             ..Default::default()
         })
         .with(NavJumpLooped);
 
         widget!{
-            (#{key} nav_vertical_box: {props} [
+            (#{key} nav_vertical_box: {nav_props} [
                 {title}
                 {content}
             ])
@@ -0,0 +1,39 @@
+use crate::ui::components::app::app;
+use raui_core::prelude::*;
+use raui_material::component::containers::breadcrumb_box::BreadcrumbProps;
+use raui_material::component::containers::breadcrumb_paper::breadcrumb_paper;
+
+fn crumb(key: &str, text: &str) -> WidgetNode {
+    widget! {
+        (#{key.to_owned()} text_box: {TextBoxProps {
+            text: text.to_owned(),
+            ..Default::default()
+        }})
+    }
+}
+
+pub fn home(context: WidgetContext) -> WidgetNode {
+    let WidgetContext { key, .. } = context;
+
+    let title = widget! {
+        (#{"title"} breadcrumb_paper: {BreadcrumbProps::default()} [
+            {crumb("home", "Home")}
+            {crumb("library", "Library")}
+            {crumb("current", "Current Item")}
+        ])
+    };
+
+    let content = widget! {
+        (#{"content"} text_box: {TextBoxProps {
+            text: "Welcome!".to_owned(),
+            ..Default::default()
+        }})
+    };
+
+    widget! {
+        (#{key} app: {Props::default()} {
+            title: {title}
+            content: {content}
+        })
+    }
+}
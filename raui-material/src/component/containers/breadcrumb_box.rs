@@ -0,0 +1,181 @@
+use raui_core::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(PropsData, Debug, Clone, Serialize, Deserialize)]
+pub struct BreadcrumbProps {
+    pub separator: String,
+    pub collapse_after: Option<usize>,
+}
+
+impl Default for BreadcrumbProps {
+    fn default() -> Self {
+        Self {
+            separator: "/".to_owned(),
+            collapse_after: None,
+        }
+    }
+}
+
+#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize)]
+struct BreadcrumbState {
+    expanded: bool,
+}
+
+#[derive(MessageData, Debug, Clone)]
+pub struct BreadcrumbSegmentMessage {
+    pub sender: WidgetId,
+    pub index: usize,
+}
+
+#[derive(MessageData, Debug, Clone)]
+struct BreadcrumbExpandMessage;
+
+fn use_breadcrumb(context: &mut WidgetContext) {
+    context.life_cycle.change(|context| {
+        for message in context.messenger.messages {
+            if message
+                .as_any()
+                .downcast_ref::<BreadcrumbExpandMessage>()
+                .is_some()
+            {
+                context.state.write(BreadcrumbState { expanded: true });
+            } else if let Some(message) = message.as_any().downcast_ref::<BreadcrumbSegmentMessage>()
+            {
+                context.signals.write(message.clone());
+            }
+        }
+    });
+}
+
+#[pre_hooks(use_breadcrumb)]
+pub fn nav_breadcrumb_box(mut context: WidgetContext) -> WidgetNode {
+    let WidgetContext {
+        id,
+        key,
+        props,
+        state,
+        listed_slots,
+        ..
+    } = context;
+
+    let BreadcrumbProps {
+        separator,
+        collapse_after,
+    } = props.read_cloned_or_default();
+    let BreadcrumbState { expanded } = state.read_cloned_or_default();
+    let notify: WidgetIdOrRef = id.to_owned().into();
+    let count = listed_slots.len();
+
+    let collapse_range = match collapse_after {
+        Some(collapse_after) if !expanded && count > collapse_after + 1 => {
+            Some(collapse_after..count.saturating_sub(1))
+        }
+        _ => None,
+    };
+
+    let mut items = Vec::with_capacity(count * 2);
+    let mut skipped = false;
+
+    for (index, slot) in listed_slots.into_iter().enumerate() {
+        if let Some(range) = &collapse_range {
+            if range.contains(&index) {
+                if !skipped {
+                    skipped = true;
+                    items.push(widget! {
+                        (#{"collapsed"} breadcrumb_segment: {BreadcrumbSegmentProps {
+                            index,
+                            collapsed: true,
+                            notify: notify.clone(),
+                        }} [
+                            (#{"label"} text_box: {TextBoxProps {
+                                text: "…".to_owned(),
+                                ..Default::default()
+                            }})
+                        ])
+                    });
+                    if index + 1 < count {
+                        items.push(separator_node(index, &separator));
+                    }
+                }
+                continue;
+            }
+        }
+
+        items.push(widget! {
+            (#{index} breadcrumb_segment: {BreadcrumbSegmentProps {
+                index,
+                collapsed: false,
+                notify: notify.clone(),
+            }} [ {slot} ])
+        });
+        if index + 1 < count {
+            items.push(separator_node(index, &separator));
+        }
+    }
+
+    widget! {
+        (#{key} nav_horizontal_box: {HorizontalBoxProps::default()} |[ items ]|)
+    }
+}
+
+fn separator_node(index: usize, separator: &str) -> WidgetNode {
+    widget! {
+        (#{format!("separator-{index}")} text_box: {TextBoxProps {
+            text: separator.to_owned(),
+            ..Default::default()
+        }})
+    }
+}
+
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+struct BreadcrumbSegmentProps {
+    index: usize,
+    collapsed: bool,
+    notify: WidgetIdOrRef,
+}
+
+fn use_breadcrumb_segment(context: &mut WidgetContext) {
+    context.life_cycle.change(|context| {
+        let BreadcrumbSegmentProps {
+            index,
+            collapsed,
+            notify,
+        } = context.props.read_cloned_or_default();
+
+        for message in context.messenger.messages {
+            if let Some(ButtonNotifyMessage { state, .. }) =
+                message.as_any().downcast_ref::<ButtonNotifyMessage>()
+            {
+                if state.trigger {
+                    if collapsed {
+                        context
+                            .messenger
+                            .write(notify.clone(), BreadcrumbExpandMessage);
+                    } else {
+                        context.messenger.write(
+                            notify.clone(),
+                            BreadcrumbSegmentMessage {
+                                sender: context.id.to_owned(),
+                                index,
+                            },
+                        );
+                    }
+                }
+            }
+        }
+    });
+}
+
+#[pre_hooks(use_breadcrumb_segment, use_button_notified_state)]
+fn breadcrumb_segment(mut context: WidgetContext) -> WidgetNode {
+    let WidgetContext {
+        id,
+        key,
+        listed_slots,
+        ..
+    } = context;
+
+    widget! {
+        (#{key} button: {ButtonNotifyProps(id.to_owned().into())} |[ listed_slots ]|)
+    }
+}
@@ -0,0 +1,19 @@
+use crate::component::containers::breadcrumb_box::nav_breadcrumb_box;
+use crate::component::containers::paper::paper;
+use raui_core::prelude::*;
+
+pub fn breadcrumb_paper(context: WidgetContext) -> WidgetNode {
+    let WidgetContext {
+        idref,
+        key,
+        props,
+        listed_slots,
+        ..
+    } = context;
+
+    widget! {
+        (#{key} | {idref.cloned()} paper: {props.clone()} [
+            (#{"breadcrumb"} nav_breadcrumb_box: {props.clone()} |[ listed_slots ]|)
+        ])
+    }
+}
@@ -1,15 +1,34 @@
 use crate::component::containers::paper::paper;
+use crate::component::theme::use_theme;
 use raui_core::prelude::*;
 
+fn with_themed_separation(props: Props, shared_props: &SharedProps) -> Props {
+    if props.read::<GridBoxProps>().is_some() {
+        return props;
+    }
+
+    let separation = use_theme(shared_props).content_separation;
+    props.with(GridBoxProps {
+        separation: Vec2 {
+            x: separation,
+            y: separation,
+        },
+        ..Default::default()
+    })
+}
+
 pub fn nav_grid_paper(context: WidgetContext) -> WidgetNode {
     let WidgetContext {
         idref,
         key,
         props,
+        shared_props,
         listed_slots,
         ..
     } = context;
 
+    let props = with_themed_separation(props, &shared_props);
+
     widget! {
         (#{key} | {idref.cloned()} paper: {props.clone()} [
             (#{"grid"} nav_grid_box: {props.clone()} |[ listed_slots ]|)
@@ -22,10 +41,13 @@ pub fn grid_paper(context: WidgetContext) -> WidgetNode {
         idref,
         key,
         props,
+        shared_props,
         listed_slots,
         ..
     } = context;
 
+    let props = with_themed_separation(props, &shared_props);
+
     widget! {
         (#{key} | {idref.cloned()} paper: {props.clone()} [
             (#{"grid"} grid_box: {props.clone()} |[ listed_slots ]|)
@@ -0,0 +1,79 @@
+use crate::component::theme::use_theme;
+use raui_core::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PaperProps {
+    pub background: Option<String>,
+    pub frame: Option<String>,
+    pub frame_width: Option<f32>,
+}
+
+pub fn paper(context: WidgetContext) -> WidgetNode {
+    let WidgetContext {
+        key,
+        props,
+        shared_props,
+        listed_slots,
+        ..
+    } = context;
+
+    let theme = use_theme(&shared_props);
+    let PaperProps {
+        background,
+        frame,
+        frame_width,
+    } = props.read_cloned_or_default();
+
+    let background = theme.token_color(background.as_deref().unwrap_or("surface"));
+    let frame = theme.token_color(frame.as_deref().unwrap_or("primary"));
+    let frame_width = frame_width.unwrap_or(theme.frame_width);
+    let background_inset = ContentBoxItemLayout {
+        margin: Rect {
+            left: frame_width,
+            right: frame_width,
+            top: frame_width,
+            bottom: frame_width,
+        },
+        ..Default::default()
+    };
+    let content_layout = ContentBoxItemLayout {
+        margin: Rect {
+            left: theme.paper_separation,
+            right: theme.paper_separation,
+            top: theme.paper_separation,
+            bottom: theme.paper_separation,
+        },
+        ..Default::default()
+    };
+
+    let mut children = vec![
+        widget! {
+            (#{"frame"} image_box: {ImageBoxProps {
+                material: ImageBoxMaterial::Color(ImageBoxColor {
+                    color: frame,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }})
+        },
+        widget! {
+            (#{"background"} image_box: {Props::new(ImageBoxProps {
+                material: ImageBoxMaterial::Color(ImageBoxColor {
+                    color: background,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }).with(background_inset)})
+        },
+    ];
+    children.extend(
+        listed_slots
+            .into_iter()
+            .map(|slot| slot.remap_props(|props| props.with(content_layout))),
+    );
+
+    widget! {
+        (#{key} content_box: {ContentBoxProps::default()} |[ children ]|)
+    }
+}
@@ -0,0 +1,230 @@
+use crate::component::theme::use_theme;
+use raui_core::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize)]
+pub struct TabsBoxProps {
+    pub active: usize,
+    pub separation: f32,
+    pub closeable: bool,
+}
+
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+pub struct TabProps {
+    pub label: String,
+    pub icon: Option<String>,
+}
+
+#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize)]
+struct TabsBoxState {
+    active: usize,
+}
+
+#[derive(MessageData, Debug, Clone)]
+pub struct TabsBoxCloseMessage {
+    pub sender: WidgetId,
+    pub index: usize,
+}
+
+#[derive(MessageData, Debug, Clone)]
+struct TabsBoxSelectMessage {
+    index: usize,
+}
+
+fn use_tabs_box(context: &mut WidgetContext) {
+    context.life_cycle.mount(|context| {
+        let TabsBoxProps { active, .. } = context.props.read_cloned_or_default();
+        context.state.write(TabsBoxState { active });
+    });
+
+    context.life_cycle.change(|context| {
+        for message in context.messenger.messages {
+            if let Some(TabsBoxSelectMessage { index }) = message.as_any().downcast_ref() {
+                context.state.write(TabsBoxState { active: *index });
+            } else if let Some(message) = message.as_any().downcast_ref::<TabsBoxCloseMessage>() {
+                context.signals.write(message.clone());
+            }
+        }
+    });
+}
+
+#[pre_hooks(use_tabs_box)]
+pub fn nav_tabs_box(mut context: WidgetContext) -> WidgetNode {
+    let WidgetContext {
+        id,
+        key,
+        props,
+        state,
+        listed_slots,
+        ..
+    } = context;
+
+    let TabsBoxProps {
+        separation,
+        closeable,
+        ..
+    } = props.read_cloned_or_default();
+    let TabsBoxState { active } = state.read_cloned_or_default();
+    let notify: WidgetIdOrRef = id.to_owned().into();
+
+    let tabs = listed_slots
+        .iter()
+        .enumerate()
+        .map(|(index, slot)| {
+            let TabProps { label, icon } = slot
+                .props()
+                .and_then(|props| props.read_cloned::<TabProps>().ok())
+                .unwrap_or_default();
+
+            widget! {
+                (#{index} tab_button: {TabButtonProps {
+                    label,
+                    icon,
+                    closeable,
+                    index,
+                    selected: index == active,
+                    notify: notify.clone(),
+                }})
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let content = listed_slots.into_iter().nth(active).unwrap_or_default();
+
+    widget! {
+        (#{key} vertical_box: {VerticalBoxProps { separation, ..Default::default() }} [
+            (#{"header"} nav_horizontal_box: {HorizontalBoxProps { separation, ..Default::default() }} |[ tabs ]|)
+            (#{"content"} content_box: {ContentBoxProps::default()} [
+                {content}
+            ])
+        ])
+    }
+}
+
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+struct TabButtonProps {
+    label: String,
+    icon: Option<String>,
+    closeable: bool,
+    selected: bool,
+    index: usize,
+    notify: WidgetIdOrRef,
+}
+
+fn use_tab_button(context: &mut WidgetContext) {
+    context.life_cycle.change(|context| {
+        let TabButtonProps { index, notify, .. } = context.props.read_cloned_or_default();
+
+        for message in context.messenger.messages {
+            if let Some(ButtonNotifyMessage { state, .. }) =
+                message.as_any().downcast_ref::<ButtonNotifyMessage>()
+            {
+                if state.trigger {
+                    context
+                        .messenger
+                        .write(notify.clone(), TabsBoxSelectMessage { index });
+                }
+            }
+        }
+    });
+}
+
+#[pre_hooks(use_tab_button, use_button_notified_state)]
+fn tab_button(mut context: WidgetContext) -> WidgetNode {
+    let WidgetContext {
+        id,
+        key,
+        props,
+        shared_props,
+        ..
+    } = context;
+
+    let label_style = use_theme(&shared_props).token_text_style("primary");
+
+    let TabButtonProps {
+        label,
+        icon,
+        closeable,
+        index,
+        notify,
+        ..
+    } = props.read_cloned_or_default();
+
+    let icon = icon.map(|icon| {
+        widget! {
+            (#{"icon"} image_box: {ImageBoxProps {
+                material: ImageBoxMaterial::Image(ImageBoxImage {
+                    id: icon,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }})
+        }
+    });
+
+    let close = if closeable {
+        Some(widget! {
+            (#{"close"} tab_close_button: {TabCloseButtonProps {
+                index,
+                notify: notify.clone(),
+            }})
+        })
+    } else {
+        None
+    };
+
+    widget! {
+        (#{key} button: {ButtonNotifyProps(id.to_owned().into())} [
+            (#{"label"} text_box: {TextBoxProps {
+                text: label,
+                color: label_style.color,
+                font: TextBoxFont {
+                    size: label_style.size,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }})
+            {Vec::from_iter(icon)}
+            {Vec::from_iter(close)}
+        ])
+    }
+}
+
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+struct TabCloseButtonProps {
+    index: usize,
+    notify: WidgetIdOrRef,
+}
+
+fn use_tab_close_button(context: &mut WidgetContext) {
+    context.life_cycle.change(|context| {
+        let TabCloseButtonProps { index, notify } = context.props.read_cloned_or_default();
+
+        for message in context.messenger.messages {
+            if let Some(ButtonNotifyMessage { state, .. }) =
+                message.as_any().downcast_ref::<ButtonNotifyMessage>()
+            {
+                if state.trigger {
+                    context.messenger.write(
+                        notify.clone(),
+                        TabsBoxCloseMessage {
+                            sender: context.id.to_owned(),
+                            index,
+                        },
+                    );
+                }
+            }
+        }
+    });
+}
+
+#[pre_hooks(use_tab_close_button, use_button_notified_state)]
+fn tab_close_button(mut context: WidgetContext) -> WidgetNode {
+    let WidgetContext { id, key, .. } = context;
+
+    widget! {
+        (#{key} button: {ButtonNotifyProps(id.to_owned().into())} [
+            (#{"icon"} text_box: {TextBoxProps { text: "x".to_owned(), ..Default::default() }})
+        ])
+    }
+}
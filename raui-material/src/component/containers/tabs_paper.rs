@@ -0,0 +1,19 @@
+use crate::component::containers::paper::paper;
+use crate::component::containers::tabs_box::nav_tabs_box;
+use raui_core::prelude::*;
+
+pub fn tabs_paper(context: WidgetContext) -> WidgetNode {
+    let WidgetContext {
+        idref,
+        key,
+        props,
+        listed_slots,
+        ..
+    } = context;
+
+    widget! {
+        (#{key} | {idref.cloned()} paper: {props.clone()} [
+            (#{"tabs"} nav_tabs_box: {props.clone()} |[ listed_slots ]|)
+        ])
+    }
+}
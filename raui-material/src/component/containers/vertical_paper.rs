@@ -1,15 +1,31 @@
 use crate::component::containers::paper::paper;
+use crate::component::theme::use_theme;
 use raui_core::prelude::*;
 
+fn with_themed_separation(props: Props, shared_props: &SharedProps) -> Props {
+    if props.read::<VerticalBoxProps>().is_some() {
+        return props;
+    }
+
+    let separation = use_theme(shared_props).content_separation;
+    props.with(VerticalBoxProps {
+        separation,
+        ..Default::default()
+    })
+}
+
 pub fn nav_vertical_paper(context: WidgetContext) -> WidgetNode {
     let WidgetContext {
         idref,
         key,
         props,
+        shared_props,
         listed_slots,
         ..
     } = context;
 
+    let props = with_themed_separation(props, &shared_props);
+
     widget! {
         (#{key} | {idref.cloned()} paper: {props.clone()} [
             (#{"vertical"} nav_vertical_box: {props.clone()} |[ listed_slots ]|)
@@ -22,10 +38,13 @@ pub fn vertical_paper(context: WidgetContext) -> WidgetNode {
         idref,
         key,
         props,
+        shared_props,
         listed_slots,
         ..
     } = context;
 
+    let props = with_themed_separation(props, &shared_props);
+
     widget! {
         (#{key} | {idref.cloned()} paper: {props.clone()} [
             (#{"vertical"} vertical_box: {props.clone()} |[ listed_slots ]|)
@@ -0,0 +1,308 @@
+use raui_core::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WorkspacePanel {
+    Left,
+    Right,
+    Bottom,
+}
+
+#[derive(PropsData, Debug, Copy, Clone, Serialize, Deserialize)]
+pub struct WorkspaceLayoutProps {
+    pub left_size: f32,
+    pub right_size: f32,
+    pub bottom_size: f32,
+    pub left_collapsed: bool,
+    pub right_collapsed: bool,
+    pub bottom_collapsed: bool,
+}
+
+impl Default for WorkspaceLayoutProps {
+    fn default() -> Self {
+        Self {
+            left_size: 200.0,
+            right_size: 200.0,
+            bottom_size: 160.0,
+            left_collapsed: false,
+            right_collapsed: false,
+            bottom_collapsed: false,
+        }
+    }
+}
+
+const SPLITTER_STEP: f32 = 16.0;
+const SPLITTER_THICKNESS: f32 = 4.0;
+const COLLAPSE_TOGGLE_SIZE: f32 = 24.0;
+
+/// Panels docked after their splitter (right, bottom) sit on the opposite
+/// side of the resize handle from `Left`, so the same trigger/context
+/// gesture has to flip sign to keep "grow" pointed the same physical
+/// direction across the whole workspace.
+fn splitter_sign(panel: WorkspacePanel) -> f32 {
+    match panel {
+        WorkspacePanel::Left => 1.0,
+        WorkspacePanel::Right | WorkspacePanel::Bottom => -1.0,
+    }
+}
+
+#[derive(MessageData, Debug, Copy, Clone)]
+pub struct WorkspaceResizeMessage {
+    pub panel: WorkspacePanel,
+    pub size: f32,
+}
+
+#[derive(MessageData, Debug, Copy, Clone)]
+pub struct WorkspaceCollapseMessage {
+    pub panel: WorkspacePanel,
+    pub collapsed: bool,
+}
+
+#[derive(MessageData, Debug, Copy, Clone)]
+struct SplitterDragMessage {
+    panel: WorkspacePanel,
+    delta: f32,
+}
+
+#[derive(MessageData, Debug, Copy, Clone)]
+struct CollapseToggleMessage {
+    panel: WorkspacePanel,
+}
+
+fn use_workspace(context: &mut WidgetContext) {
+    context.life_cycle.mount(|context| {
+        let layout = context.props.read_cloned_or_default::<WorkspaceLayoutProps>();
+        context.state.write(layout);
+    });
+
+    context.life_cycle.change(|context| {
+        let mut layout = context.state.read_cloned_or_default::<WorkspaceLayoutProps>();
+
+        for message in context.messenger.messages {
+            if let Some(SplitterDragMessage { panel, delta }) = message.as_any().downcast_ref() {
+                match panel {
+                    WorkspacePanel::Left => layout.left_size = (layout.left_size + delta).max(0.0),
+                    WorkspacePanel::Right => {
+                        layout.right_size = (layout.right_size + delta).max(0.0)
+                    }
+                    WorkspacePanel::Bottom => {
+                        layout.bottom_size = (layout.bottom_size + delta).max(0.0)
+                    }
+                }
+                context.signals.write(WorkspaceResizeMessage {
+                    panel: *panel,
+                    size: match panel {
+                        WorkspacePanel::Left => layout.left_size,
+                        WorkspacePanel::Right => layout.right_size,
+                        WorkspacePanel::Bottom => layout.bottom_size,
+                    },
+                });
+            } else if let Some(CollapseToggleMessage { panel }) = message.as_any().downcast_ref() {
+                let collapsed = match panel {
+                    WorkspacePanel::Left => {
+                        layout.left_collapsed = !layout.left_collapsed;
+                        layout.left_collapsed
+                    }
+                    WorkspacePanel::Right => {
+                        layout.right_collapsed = !layout.right_collapsed;
+                        layout.right_collapsed
+                    }
+                    WorkspacePanel::Bottom => {
+                        layout.bottom_collapsed = !layout.bottom_collapsed;
+                        layout.bottom_collapsed
+                    }
+                };
+                context.signals.write(WorkspaceCollapseMessage {
+                    panel: *panel,
+                    collapsed,
+                });
+            }
+        }
+
+        context.state.write(layout);
+    });
+}
+
+fn panel_layout(size: f32, collapsed: bool) -> FlexBoxItemLayout {
+    FlexBoxItemLayout {
+        basis: Some(if collapsed { 0.0 } else { size }),
+        grow: 0.0,
+        shrink: 0.0,
+        ..Default::default()
+    }
+}
+
+#[pre_hooks(use_workspace)]
+pub fn workspace(mut context: WidgetContext) -> WidgetNode {
+    let WidgetContext {
+        id,
+        key,
+        state,
+        named_slots,
+        ..
+    } = context;
+    unpack_named_slots!(named_slots => { left, right, bottom, center });
+    let notify: WidgetIdOrRef = id.to_owned().into();
+
+    let WorkspaceLayoutProps {
+        left_size,
+        right_size,
+        bottom_size,
+        left_collapsed,
+        right_collapsed,
+        bottom_collapsed,
+    } = state.read_cloned_or_default();
+
+    let left = left.remap_props(|props| props.with(panel_layout(left_size, left_collapsed)));
+    let right = right.remap_props(|props| props.with(panel_layout(right_size, right_collapsed)));
+    let bottom =
+        bottom.remap_props(|props| props.with(panel_layout(bottom_size, bottom_collapsed)));
+
+    let upper_row = widget! {
+        (#{"upper"} nav_horizontal_box: {HorizontalBoxProps::default()} [
+            {left}
+            (#{"left-collapse"} collapse_toggle: {CollapseToggleProps { panel: WorkspacePanel::Left, notify: notify.clone() }})
+            (#{"left-splitter"} splitter: {SplitterProps { panel: WorkspacePanel::Left, notify: notify.clone() }})
+            {center}
+            (#{"right-splitter"} splitter: {SplitterProps { panel: WorkspacePanel::Right, notify: notify.clone() }})
+            (#{"right-collapse"} collapse_toggle: {CollapseToggleProps { panel: WorkspacePanel::Right, notify: notify.clone() }})
+            {right}
+        ])
+    };
+
+    widget! {
+        (#{key} nav_vertical_box: {VerticalBoxProps::default()} [
+            {upper_row}
+            (#{"bottom-collapse"} collapse_toggle: {CollapseToggleProps { panel: WorkspacePanel::Bottom, notify: notify.clone() }})
+            (#{"bottom-splitter"} splitter: {SplitterProps { panel: WorkspacePanel::Bottom, notify }})
+            {bottom}
+        ])
+    }
+}
+
+#[derive(PropsData, Debug, Clone, Serialize, Deserialize)]
+struct SplitterProps {
+    panel: WorkspacePanel,
+    notify: WidgetIdOrRef,
+}
+
+impl Default for SplitterProps {
+    fn default() -> Self {
+        Self {
+            panel: WorkspacePanel::Left,
+            notify: Default::default(),
+        }
+    }
+}
+
+fn use_splitter(context: &mut WidgetContext) {
+    context.life_cycle.change(|context| {
+        let SplitterProps { panel, notify } = context.props.read_cloned_or_default();
+
+        for message in context.messenger.messages {
+            if let Some(ButtonNotifyMessage { state, .. }) =
+                message.as_any().downcast_ref::<ButtonNotifyMessage>()
+            {
+                let sign = splitter_sign(panel);
+                if state.trigger {
+                    context.messenger.write(
+                        notify.clone(),
+                        SplitterDragMessage {
+                            panel,
+                            delta: SPLITTER_STEP * sign,
+                        },
+                    );
+                } else if state.context {
+                    context.messenger.write(
+                        notify.clone(),
+                        SplitterDragMessage {
+                            panel,
+                            delta: -SPLITTER_STEP * sign,
+                        },
+                    );
+                }
+            }
+        }
+    });
+}
+
+// Fixed-step stand-in for a draggable splitter: raui_core doesn't surface
+// pointer motion to widgets, so trigger/context bump the panel by
+// SPLITTER_STEP instead of tracking a continuous drag delta.
+#[pre_hooks(use_splitter, use_button_notified_state)]
+fn splitter(mut context: WidgetContext) -> WidgetNode {
+    let WidgetContext { id, key, .. } = context;
+
+    let layout = FlexBoxItemLayout {
+        basis: Some(SPLITTER_THICKNESS),
+        grow: 0.0,
+        shrink: 0.0,
+        ..Default::default()
+    };
+
+    widget! {
+        (#{key} button: {Props::new(NavItemActive)
+            .with(layout)
+            .with(ButtonNotifyProps(id.to_owned().into()))
+        } [
+            (#{"handle"} image_box: {ImageBoxProps {
+                material: ImageBoxMaterial::Color(ImageBoxColor {
+                    color: Color { r: 0.0, g: 0.0, b: 0.0, a: 0.25 },
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }})
+        ])
+    }
+}
+
+#[derive(PropsData, Debug, Clone, Serialize, Deserialize)]
+struct CollapseToggleProps {
+    panel: WorkspacePanel,
+    notify: WidgetIdOrRef,
+}
+
+impl Default for CollapseToggleProps {
+    fn default() -> Self {
+        Self {
+            panel: WorkspacePanel::Left,
+            notify: Default::default(),
+        }
+    }
+}
+
+fn use_collapse_toggle(context: &mut WidgetContext) {
+    context.life_cycle.change(|context| {
+        let CollapseToggleProps { panel, notify } = context.props.read_cloned_or_default();
+
+        for message in context.messenger.messages {
+            if let Some(ButtonNotifyMessage { state, .. }) =
+                message.as_any().downcast_ref::<ButtonNotifyMessage>()
+            {
+                if state.trigger {
+                    context
+                        .messenger
+                        .write(notify.clone(), CollapseToggleMessage { panel });
+                }
+            }
+        }
+    });
+}
+
+#[pre_hooks(use_collapse_toggle, use_button_notified_state)]
+fn collapse_toggle(mut context: WidgetContext) -> WidgetNode {
+    let WidgetContext { id, key, .. } = context;
+
+    let layout = FlexBoxItemLayout {
+        basis: Some(COLLAPSE_TOGGLE_SIZE),
+        grow: 0.0,
+        shrink: 0.0,
+        ..Default::default()
+    };
+
+    widget! {
+        (#{key} button: {Props::new(layout).with(ButtonNotifyProps(id.to_owned().into()))} [
+            (#{"icon"} text_box: {TextBoxProps { text: "«".to_owned(), ..Default::default() }})
+        ])
+    }
+}
@@ -0,0 +1,25 @@
+use crate::component::containers::paper::paper;
+use crate::component::containers::workspace::workspace;
+use raui_core::prelude::*;
+
+pub fn workspace_paper(context: WidgetContext) -> WidgetNode {
+    let WidgetContext {
+        idref,
+        key,
+        props,
+        named_slots,
+        ..
+    } = context;
+    unpack_named_slots!(named_slots => { left, right, bottom, center });
+
+    widget! {
+        (#{key} | {idref.cloned()} paper: {props.clone()} [
+            (#{"workspace"} workspace: {props.clone()} {
+                left: {left}
+                right: {right}
+                bottom: {bottom}
+                center: {center}
+            })
+        ])
+    }
+}
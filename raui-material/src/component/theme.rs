@@ -0,0 +1,125 @@
+use raui_core::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextStyle {
+    pub color: Color,
+    pub size: f32,
+}
+
+impl Default for TextStyle {
+    fn default() -> Self {
+        Self {
+            color: Color {
+                r: 0.0,
+                g: 0.0,
+                b: 0.0,
+                a: 1.0,
+            },
+            size: 16.0,
+        }
+    }
+}
+
+#[derive(PropsData, Debug, Clone, Serialize, Deserialize)]
+pub struct ThemeProps {
+    pub palette: HashMap<String, Color>,
+    pub text_styles: HashMap<String, TextStyle>,
+    pub content_separation: f32,
+    pub paper_separation: f32,
+    pub frame_width: f32,
+}
+
+impl Default for ThemeProps {
+    fn default() -> Self {
+        let mut palette = HashMap::new();
+        palette.insert(
+            "primary".to_owned(),
+            Color {
+                r: 0.2,
+                g: 0.4,
+                b: 0.9,
+                a: 1.0,
+            },
+        );
+        palette.insert(
+            "surface".to_owned(),
+            Color {
+                r: 0.95,
+                g: 0.95,
+                b: 0.95,
+                a: 1.0,
+            },
+        );
+        palette.insert(
+            "accent".to_owned(),
+            Color {
+                r: 0.9,
+                g: 0.3,
+                b: 0.2,
+                a: 1.0,
+            },
+        );
+
+        let mut text_styles = HashMap::new();
+        text_styles.insert(
+            "primary".to_owned(),
+            TextStyle {
+                color: Color {
+                    r: 0.2,
+                    g: 0.4,
+                    b: 0.9,
+                    a: 1.0,
+                },
+                size: 16.0,
+            },
+        );
+        text_styles.insert(
+            "surface".to_owned(),
+            TextStyle {
+                color: Color {
+                    r: 0.1,
+                    g: 0.1,
+                    b: 0.1,
+                    a: 1.0,
+                },
+                size: 16.0,
+            },
+        );
+        text_styles.insert(
+            "accent".to_owned(),
+            TextStyle {
+                color: Color {
+                    r: 0.9,
+                    g: 0.3,
+                    b: 0.2,
+                    a: 1.0,
+                },
+                size: 16.0,
+            },
+        );
+
+        Self {
+            palette,
+            text_styles,
+            content_separation: 16.0,
+            paper_separation: 16.0,
+            frame_width: 4.0,
+        }
+    }
+}
+
+impl ThemeProps {
+    pub fn token_color(&self, role: &str) -> Color {
+        self.palette.get(role).copied().unwrap_or_default()
+    }
+
+    pub fn token_text_style(&self, role: &str) -> TextStyle {
+        self.text_styles.get(role).cloned().unwrap_or_default()
+    }
+}
+
+pub fn use_theme(shared_props: &SharedProps) -> ThemeProps {
+    shared_props.read_cloned_or_default::<ThemeProps>()
+}